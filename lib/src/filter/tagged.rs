@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use globset::GlobMatcher;
@@ -10,7 +10,7 @@ use crate::error::RuntimeError;
 use crate::event::{Event, Tag};
 use crate::filter::Filterer;
 
-mod parse;
+pub mod parse;
 pub mod swaplock;
 
 pub struct TaggedFilterer {
@@ -115,15 +115,30 @@ impl TaggedFilterer {
 		trace!(?tag, matcher=?filter.on, "matching filter to tag");
 		match (tag, filter.on) {
 			(tag, Matcher::Tag) => filter.matches(tag.discriminant_name()),
-			(Tag::Path(_path), Matcher::Path) => todo!("tagged filterer: path matcher"),
+			(Tag::Path(path), Matcher::Path) => {
+				if let Some(in_path) = &filter.in_path {
+					let in_path = self.resolve_in_path(in_path);
+					if let Ok(subpath) = path.strip_prefix(&in_path) {
+						trace!(?in_path, ?subpath, "path is within in_path, matching on remainder");
+						filter.matches(subpath.to_string_lossy())
+					} else {
+						trace!(?in_path, ?path, "path is not within in_path, failing");
+						Ok(false)
+					}
+				} else {
+					filter.matches(path.to_string_lossy())
+				}
+			}
 			(Tag::FileEventKind(kind), Matcher::FileEventKind) => {
 				filter.matches(format!("{:?}", kind))
 			}
 			(Tag::Source(src), Matcher::Source) => filter.matches(src.to_string()),
 			(Tag::Process(pid), Matcher::Process) => filter.matches(pid.to_string()),
-			(Tag::Signal(_sig), Matcher::Signal) => todo!("tagged filterer: signal matcher"),
-			(Tag::ProcessCompletion(_oes), Matcher::ProcessCompletion) => {
-				todo!("tagged filterer: completion matcher")
+			(Tag::Signal(sig), Matcher::Signal) => {
+				filter.matches(Self::canonical_signal_name(&sig.to_string()))
+			}
+			(Tag::ProcessCompletion(oes), Matcher::ProcessCompletion) => {
+				filter.matches(Self::process_completion_subject(oes))
 			}
 			(tag, matcher) => {
 				trace!(?tag, ?matcher, "no match for tag, skipping");
@@ -133,6 +148,90 @@ impl TaggedFilterer {
 		.map(Some)
 	}
 
+	/// Resolves a filter's `in_path` against the filterer's root or workdir.
+	///
+	/// Absolute `in_path`s are resolved against `root` (the project root), so that a filter
+	/// written against the project is portable regardless of where watchexec is invoked from.
+	/// Relative `in_path`s are resolved against `workdir` (where watchexec is running), matching
+	/// how a user would type a path at the shell.
+	fn resolve_in_path(&self, in_path: &Path) -> PathBuf {
+		if in_path.is_absolute() {
+			// Drop the platform's root/prefix components (`/` on Unix, `C:\` on Windows) so the
+			// remainder can be joined onto `root` instead of replacing it outright, which is
+			// what `PathBuf::join` would do if handed another absolute path.
+			use std::path::Component;
+			let relative: PathBuf = in_path
+				.components()
+				.filter(|c| !matches!(c, Component::Prefix(_) | Component::RootDir))
+				.collect();
+			self.root.join(relative)
+		} else {
+			self.workdir.join(in_path)
+		}
+	}
+
+	/// Normalises a signal name or number into the canonical form filters compare against: an
+	/// uppercase name without the `SIG` prefix. `SIGHUP`, `sighup`, `HUP` and `1` all normalise
+	/// to `HUP`, so a filter author doesn't need to know which spelling an event carries.
+	fn canonical_signal_name(input: &str) -> String {
+		let input = input.trim();
+		if let Ok(num) = input.parse::<i32>() {
+			return Self::signal_name_from_number(num);
+		}
+
+		let upper = input.to_ascii_uppercase();
+		upper.strip_prefix("SIG").unwrap_or(&upper).to_string()
+	}
+
+	/// Maps common POSIX signal numbers to their canonical names; numbers with no well-known
+	/// name are passed through as-is so they can still be matched on.
+	fn signal_name_from_number(num: i32) -> String {
+		match num {
+			1 => "HUP",
+			2 => "INT",
+			3 => "QUIT",
+			6 => "ABRT",
+			9 => "KILL",
+			10 => "USR1",
+			12 => "USR2",
+			15 => "TERM",
+			18 => "CONT",
+			19 => "STOP",
+			20 => "TSTP",
+			_ => return num.to_string(),
+		}
+		.to_string()
+	}
+
+	/// Turns a process' completion status into the subject string `Matcher::ProcessCompletion`
+	/// filters compare against: `"success"`, `"exit(<code>)"`, or `"signal(SIG<NAME>)"` for
+	/// termination by signal, e.g. `"signal(SIGKILL)"`. Unlike the `Signal` matcher's canonical
+	/// form (which drops the `SIG` prefix), this keeps it, since that's the conventional way to
+	/// write a signal name on its own.
+	fn process_completion_subject(oes: &Option<std::process::ExitStatus>) -> String {
+		let status = match oes {
+			None => return "success".to_string(),
+			Some(status) => status,
+		};
+
+		if status.success() {
+			return "success".to_string();
+		}
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::process::ExitStatusExt;
+			if let Some(sig) = status.signal() {
+				return format!("signal(SIG{})", Self::signal_name_from_number(sig));
+			}
+		}
+
+		match status.code() {
+			Some(code) => format!("exit({})", code),
+			None => "failure".to_string(),
+		}
+	}
+
 	pub async fn add_filter(&self, filter: Filter) -> Result<(), RuntimeError> {
 		debug!(?filter, "adding filter to filterer");
 		self.filters
@@ -180,6 +279,10 @@ impl Filter {
 
 		trace!(op=?self.op, pat=?self.pat, ?subject, "performing filter match");
 		Ok(match (self.op, &self.pat) {
+			(Op::Auto, Pattern::Exact(pat)) => subject == pat,
+			(Op::Auto, Pattern::Regex(pat)) => pat.is_match(subject),
+			(Op::Auto, Pattern::Glob(pat)) => pat.is_match(subject),
+			(Op::Auto, Pattern::Set(set)) => set.contains(subject),
 			(Op::Equal, Pattern::Exact(pat)) => subject == pat,
 			(Op::NotEqual, Pattern::Exact(pat)) => subject != pat,
 			(Op::Regex, Pattern::Regex(pat)) => pat.is_match(subject),
@@ -261,4 +364,77 @@ impl PartialEq<Self> for Pattern {
 	}
 }
 
-impl Eq for Pattern {}
\ No newline at end of file
+impl Eq for Pattern {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn resolve_in_path_relative_joins_workdir() {
+		let filterer = TaggedFilterer::new("/root", "/root/sub");
+		assert_eq!(
+			filterer.resolve_in_path(Path::new("src")),
+			PathBuf::from("/root/sub/src")
+		);
+	}
+
+	#[test]
+	fn resolve_in_path_absolute_joins_root() {
+		let filterer = TaggedFilterer::new("/root", "/elsewhere");
+		assert_eq!(
+			filterer.resolve_in_path(Path::new("/src")),
+			PathBuf::from("/root/src")
+		);
+	}
+
+	#[cfg(windows)]
+	#[test]
+	fn resolve_in_path_windows_prefix_joins_root() {
+		let filterer = TaggedFilterer::new(r"C:\root", r"C:\elsewhere");
+		assert_eq!(
+			filterer.resolve_in_path(Path::new(r"C:\src")),
+			PathBuf::from(r"C:\root\src")
+		);
+	}
+
+	fn auto_filter(pat: Pattern) -> Filter {
+		Filter {
+			in_path: None,
+			on: Matcher::Path,
+			op: Op::Auto,
+			pat,
+			negate: false,
+		}
+	}
+
+	#[test]
+	fn auto_op_resolves_to_equal_for_exact_pattern() {
+		let filter = auto_filter(Pattern::Exact("foo".into()));
+		assert!(filter.matches("foo").unwrap());
+		assert!(!filter.matches("bar").unwrap());
+	}
+
+	#[test]
+	fn auto_op_resolves_to_glob_for_glob_pattern() {
+		let pat = globset::Glob::new("*.rs").unwrap().compile_matcher();
+		let filter = auto_filter(Pattern::Glob(pat));
+		assert!(filter.matches("main.rs").unwrap());
+		assert!(!filter.matches("main.txt").unwrap());
+	}
+
+	#[test]
+	fn auto_op_resolves_to_regex_for_regex_pattern() {
+		let filter = auto_filter(Pattern::Regex(Regex::new("^foo").unwrap()));
+		assert!(filter.matches("foobar").unwrap());
+		assert!(!filter.matches("barfoo").unwrap());
+	}
+
+	#[test]
+	fn auto_op_resolves_to_in_set_for_set_pattern() {
+		let set: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+		let filter = auto_filter(Pattern::Set(set));
+		assert!(filter.matches("a").unwrap());
+		assert!(!filter.matches("c").unwrap());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,191 @@
+//! Parses the filter-expression DSL into [`Filter`]s.
+//!
+//! A filter expression has the shape:
+//!
+//! ```text
+//! ["!"] <matcher> ["@" <in_path>] <op> <pattern>
+//! ```
+//!
+//! - `!` negates the filter (see [`Filter::negate`]).
+//! - `<matcher>` is a short name for a [`Matcher`], e.g. `path`, `fek`, `process`.
+//! - `<in_path>` restricts a `path` matcher to a subtree (see [`Filter::in_path`]).
+//! - `<op>` is one of `==`, `!=`, `~=`, `~!`, `*=`, `*!`, `:=`, `:!`, or the bare `=`.
+//! - `<pattern>` is read according to the operator: `~` ops take a regex, `*` ops a glob, `:` ops
+//!   a comma-separated set, and everything else an exact string.
+//!
+//! # Examples
+//!
+//! - `path*=src/**` matches paths glob-matching `src/**`.
+//! - `fek~=Modify` matches file event kinds whose debug form matches the regex `Modify`.
+//! - `process:=1234,5678` matches process ids `1234` or `5678`.
+use std::collections::HashSet;
+
+use globset::Glob;
+use regex::Regex;
+
+use super::{Filter, Matcher, Op, Pattern};
+
+/// Parses a filter-expression string into a [`Filter`].
+pub fn parse(expr: &str) -> Result<Filter, String> {
+	let (negate, rest) = match expr.strip_prefix('!') {
+		Some(rest) => (true, rest),
+		None => (false, expr),
+	};
+
+	let (op_index, op, op_len) = find_op(rest)
+		.ok_or_else(|| format!("no operator found in filter expression: {:?}", expr))?;
+
+	let (head, pattern) = (&rest[..op_index], &rest[op_index + op_len..]);
+
+	let (matcher_name, in_path) = match head.split_once('@') {
+		Some((matcher_name, in_path)) => (matcher_name, Some(in_path.into())),
+		None => (head, None),
+	};
+
+	let on = parse_matcher(matcher_name)?;
+	let pat = parse_pattern(op, pattern)?;
+
+	Ok(Filter {
+		in_path,
+		on,
+		op,
+		pat,
+		negate,
+	})
+}
+
+/// Finds the earliest, longest operator token in `input`, returning its byte index, the parsed
+/// [`Op`], and the token's byte length.
+fn find_op(input: &str) -> Option<(usize, Op, usize)> {
+	const TOKENS: &[(&str, Op)] = &[
+		("==", Op::Equal),
+		("!=", Op::NotEqual),
+		("~=", Op::Regex),
+		("~!", Op::NotRegex),
+		("*=", Op::Glob),
+		("*!", Op::NotGlob),
+		(":=", Op::InSet),
+		(":!", Op::NotInSet),
+		("=", Op::Auto),
+	];
+
+	input
+		.char_indices()
+		.find_map(|(index, _)| {
+			TOKENS
+				.iter()
+				.find(|(token, _)| input[index..].starts_with(token))
+				.map(|&(token, op)| (index, op, token.len()))
+		})
+}
+
+fn parse_matcher(name: &str) -> Result<Matcher, String> {
+	Ok(match name {
+		"tag" => Matcher::Tag,
+		"path" => Matcher::Path,
+		"fek" | "kind" => Matcher::FileEventKind,
+		"source" | "src" => Matcher::Source,
+		"process" | "pid" => Matcher::Process,
+		"signal" | "sig" => Matcher::Signal,
+		"complete" | "completion" => Matcher::ProcessCompletion,
+		other => return Err(format!("unknown matcher: {:?}", other)),
+	})
+}
+
+fn parse_pattern(op: Op, pattern: &str) -> Result<Pattern, String> {
+	Ok(match op {
+		Op::Regex | Op::NotRegex => {
+			Pattern::Regex(Regex::new(pattern).map_err(|err| err.to_string())?)
+		}
+		Op::Glob | Op::NotGlob => Pattern::Glob(
+			Glob::new(pattern)
+				.map_err(|err| err.to_string())?
+				.compile_matcher(),
+		),
+		Op::InSet | Op::NotInSet => {
+			Pattern::Set(pattern.split(',').map(String::from).collect::<HashSet<_>>())
+		}
+		Op::Equal | Op::NotEqual | Op::Auto => Pattern::Exact(pattern.to_string()),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bare_equals_parses_as_auto_exact() {
+		let filter = parse("path=src/main.rs").unwrap();
+		assert_eq!(filter.on, Matcher::Path);
+		assert_eq!(filter.op, Op::Auto);
+		assert_eq!(filter.pat, Pattern::Exact("src/main.rs".into()));
+		assert!(!filter.negate);
+	}
+
+	#[test]
+	fn double_equals_parses_as_equal_exact() {
+		let filter = parse("path==src/main.rs").unwrap();
+		assert_eq!(filter.op, Op::Equal);
+		assert_eq!(filter.pat, Pattern::Exact("src/main.rs".into()));
+	}
+
+	#[test]
+	fn earliest_op_token_wins_over_one_later_in_the_pattern() {
+		// The first `=` (a bare Auto) should end the matcher/op, not the `=` inside the pattern.
+		let filter = parse("path=foo=bar").unwrap();
+		assert_eq!(filter.op, Op::Auto);
+		assert_eq!(filter.pat, Pattern::Exact("foo=bar".into()));
+	}
+
+	#[test]
+	fn glob_op_parses_glob_pattern() {
+		let filter = parse("path*=src/**").unwrap();
+		assert_eq!(filter.on, Matcher::Path);
+		assert_eq!(filter.op, Op::Glob);
+		let expected = Glob::new("src/**").unwrap().compile_matcher();
+		assert_eq!(filter.pat, Pattern::Glob(expected));
+	}
+
+	#[test]
+	fn regex_op_parses_regex_pattern() {
+		let filter = parse("fek~=Modify").unwrap();
+		assert_eq!(filter.on, Matcher::FileEventKind);
+		assert_eq!(filter.op, Op::Regex);
+		match filter.pat {
+			Pattern::Regex(ref re) => assert_eq!(re.as_str(), "Modify"),
+			ref other => panic!("expected Pattern::Regex, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn in_set_op_splits_pattern_on_commas() {
+		let filter = parse("process:=1234,5678").unwrap();
+		assert_eq!(filter.on, Matcher::Process);
+		assert_eq!(filter.op, Op::InSet);
+		let expected: HashSet<String> = ["1234", "5678"].iter().map(|s| s.to_string()).collect();
+		assert_eq!(filter.pat, Pattern::Set(expected));
+	}
+
+	#[test]
+	fn leading_bang_sets_negate() {
+		let filter = parse("!path==src/main.rs").unwrap();
+		assert!(filter.negate);
+	}
+
+	#[test]
+	fn at_sign_sets_in_path() {
+		let filter = parse("path@src==main.rs").unwrap();
+		assert_eq!(filter.in_path, Some("src".into()));
+		assert_eq!(filter.pat, Pattern::Exact("main.rs".into()));
+	}
+
+	#[test]
+	fn unknown_matcher_is_an_error() {
+		assert!(parse("nonsense==foo").is_err());
+	}
+
+	#[test]
+	fn missing_operator_is_an_error() {
+		assert!(parse("path").is_err());
+	}
+}
@@ -23,6 +23,7 @@ use std::{
 };
 
 use crate::config::{Config, ConfigBuilder};
+use crate::config_file;
 
 #[deprecated(since = "1.15.0", note = "Config has moved to config::Config")]
 pub type Args = Config;
@@ -31,20 +32,119 @@ pub type Args = Config;
 pub type ArgsBuilder = ConfigBuilder;
 
 /// Clear the screen.
-#[cfg(target_family = "windows")]
+///
+/// This writes terminal control codes directly to stdout instead of shelling out to `tput`, so on
+/// terminals that understand them it works without external binaries and without spawning a
+/// process on every `--clear` cycle. On Windows consoles without VT processing support, it still
+/// runs `cmd /c cls` instead. If stdout isn't a TTY, this is a no-op.
 pub fn clear_screen() {
-// TODO: clearscreen with powershell?
-    let _ = Command::new("cmd")
-        .arg("/c")
-        .arg("tput reset || cls")
-        .status();
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout();
+    if !is_tty(&stdout) {
+        return;
+    }
+
+    #[cfg(target_family = "windows")]
+    if !windows_vt_supported(&stdout) {
+        let _ = Command::new("cmd").arg("/c").arg("cls").status();
+        return;
+    }
+
+    // Clear screen, clear scrollback, move cursor to top-left.
+    let _ = write!(stdout, "\x1b[2J\x1b[3J\x1b[H");
+    let _ = stdout.flush();
 }
 
-/// Clear the screen.
 #[cfg(target_family = "unix")]
-pub fn clear_screen() {
-// TODO: clear screen via control codes instead
-    let _ = Command::new("tput").arg("reset").status();
+fn is_tty(stdout: &std::io::Stdout) -> bool {
+    use std::os::unix::io::AsRawFd;
+
+    extern "C" {
+        fn isatty(fd: i32) -> i32;
+    }
+
+    unsafe { isatty(stdout.as_raw_fd()) != 0 }
+}
+
+#[cfg(target_family = "windows")]
+fn is_tty(stdout: &std::io::Stdout) -> bool {
+    console_mode(stdout).is_some()
+}
+
+/// Checks whether the console attached to `stdout` already has, or can be switched into, VT
+/// processing mode (i.e. understands ANSI escape codes). Older `cmd.exe` and `conhost.exe`
+/// versions don't, and need the legacy `cls` fallback instead.
+#[cfg(target_family = "windows")]
+fn windows_vt_supported(stdout: &std::io::Stdout) -> bool {
+    use std::os::windows::io::AsRawHandle;
+
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    let (handle, mode) = match console_mode(stdout) {
+        Some(mode) => (stdout.as_raw_handle(), mode),
+        None => return false,
+    };
+
+    if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+        return true;
+    }
+
+    extern "system" {
+        fn SetConsoleMode(handle: *mut std::os::raw::c_void, mode: u32) -> i32;
+    }
+
+    unsafe { SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0 }
+}
+
+#[cfg(target_family = "windows")]
+fn console_mode(stdout: &std::io::Stdout) -> Option<u32> {
+    use std::os::windows::io::AsRawHandle;
+
+    extern "system" {
+        fn GetConsoleMode(handle: *mut std::os::raw::c_void, mode: *mut u32) -> i32;
+    }
+
+    let mut mode = 0u32;
+    let ok = unsafe { GetConsoleMode(stdout.as_raw_handle(), &mut mode) != 0 };
+    if ok {
+        Some(mode)
+    } else {
+        None
+    }
+}
+
+/// Parses and registers `Config::tag_filters` against a [`TaggedFilterer`], making `--tag-filter`
+/// actually take effect. This is separate from [`get_args_impl`] because `add_filter` is async
+/// and a `TaggedFilterer` needs a `root`/`workdir` that `Config` doesn't carry; call this once
+/// the run's filterer has been built.
+pub async fn register_tag_filters(
+    filterer: &watchexec::filter::tagged::TaggedFilterer,
+    tag_filters: &[String],
+) -> error::Result<()> {
+    for expr in tag_filters {
+        let filter = watchexec::filter::tagged::parse::parse(expr)
+            .map_err(|err| format!("invalid --tag-filter {:?}: {}", expr, err))?;
+        filterer
+            .add_filter(filter)
+            .await
+            .map_err(|err| err.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Builds the `TaggedFilterer` for a run, with this config's `--tag-filter` expressions already
+/// registered against it. This is the construction site the run loop should call instead of
+/// `TaggedFilterer::new` directly whenever `Config::tag_filters` is non-empty.
+pub async fn tagged_filterer_for(
+    tag_filters: &[String],
+    root: impl Into<PathBuf>,
+    workdir: impl Into<PathBuf>,
+) -> error::Result<std::sync::Arc<watchexec::filter::tagged::TaggedFilterer>> {
+    let filterer = watchexec::filter::tagged::TaggedFilterer::new(root, workdir);
+    register_tag_filters(&filterer, tag_filters).await?;
+    Ok(filterer)
 }
 
 #[deprecated(since = "1.15.0", note = "this will be removed from the library API. use the builder")]
@@ -133,6 +233,13 @@ where
                  .multiple(true)
                  .takes_value(true)
                  .value_name("pattern"))
+        .arg(Arg::with_name("tag-filter")
+                 .help("Add a tagged filter expression, e.g. 'path*=src/**' (see the filter DSL docs)")
+                 .long("tag-filter")
+                 .number_of_values(1)
+                 .multiple(true)
+                 .takes_value(true)
+                 .value_name("expression"))
         .arg(Arg::with_name("no-vcs-ignore")
                  .help("Skip auto-loading of .gitignore files for filtering")
                  .long("no-vcs-ignore"))
@@ -173,23 +280,39 @@ where
 
     let mut builder = ConfigBuilder::default();
 
+    // Project-local `.watchexec.toml`, if any. CLI flags always win over its settings; it only
+    // fills in what the user didn't pass explicitly.
+    let workdir = std::env::current_dir().unwrap_or_else(|_| ".".into());
+    let file_config = config_file::discover_and_load(&workdir)?;
+
     let cmd: Vec<String> = values_t!(args.values_of("command"), String).map_err(|err| err.to_string())?;
     builder.cmd(cmd);
 
-    let paths: Vec<PathBuf> = values_t!(args.values_of("path"), String)
-        .unwrap_or_else(|_| vec![".".into()])
-        .iter()
-        .map(|string_path| string_path.into())
-        .collect();
+    let paths: Vec<PathBuf> = if args.occurrences_of("path") > 0 {
+        values_t!(args.values_of("path"), String)
+            .map_err(|err| err.to_string())?
+            .iter()
+            .map(|string_path| string_path.into())
+            .collect()
+    } else if let Some(paths) = &file_config.paths {
+        paths.clone()
+    } else {
+        vec![".".into()]
+    };
     builder.paths(paths);
 
-    // Treat --kill as --signal SIGKILL (for compatibility with deprecated syntax)
+    // Treat --kill as --signal SIGKILL (for compatibility with deprecated syntax). An explicit
+    // --signal still overrides --kill, same as before the config-file merge was added.
     if args.is_present("kill") {
         builder.signal("SIGKILL");
     }
 
     if let Some(signal) = args.value_of("signal") {
         builder.signal(signal);
+    } else if !args.is_present("kill") {
+        if let Some(signal) = &file_config.signal {
+            builder.signal(signal.as_str());
+        }
     }
 
     let mut filters = values_t!(args.values_of("filter"), String).unwrap_or_else(|_| Vec::new());
@@ -205,6 +328,12 @@ where
         }
     }
 
+    if filters.is_empty() {
+        if let Some(file_filters) = &file_config.filters {
+            filters.extend(file_filters.clone());
+        }
+    }
+
     builder.filters(filters);
 
     let mut ignores = vec![];
@@ -224,29 +353,52 @@ where
     if args.occurrences_of("no-default-ignore") == 0 {
         ignores.extend(default_ignores)
     };
-    ignores.extend(values_t!(args.values_of("ignore"), String).unwrap_or_else(|_| Vec::new()));
+
+    if args.occurrences_of("ignore") > 0 {
+        ignores.extend(values_t!(args.values_of("ignore"), String).unwrap_or_else(|_| Vec::new()));
+    } else if let Some(file_ignores) = &file_config.ignores {
+        ignores.extend(file_ignores.clone());
+    }
 
     builder.ignores(ignores);
 
+    // Validate tag-filter expressions eagerly so a typo is reported at startup rather than when
+    // the first event comes in. The `Config` only carries the raw expressions; call
+    // `register_tag_filters` with the run's `TaggedFilterer` once it's built to actually apply
+    // them (building the filterer itself happens outside `Config`, where `root`/`workdir` for it
+    // are known).
+    let tag_filters = values_t!(args.values_of("tag-filter"), String).unwrap_or_else(|_| Vec::new());
+    for expr in &tag_filters {
+        watchexec::filter::tagged::parse::parse(expr).map_err(|err| {
+            format!("invalid --tag-filter {:?}: {}", expr, err)
+        })?;
+    }
+    builder.tag_filters(tag_filters);
+
+    let polling = args.occurrences_of("poll") > 0 || file_config.poll_interval.is_some();
     if args.occurrences_of("poll") > 0 {
         builder.poll_interval(value_t!(args.value_of("poll"), u32).unwrap_or_else(|e| e.exit()));
+    } else if let Some(interval) = file_config.poll_interval {
+        builder.poll_interval(interval);
     }
 
     if args.occurrences_of("debounce") > 0 {
         builder.debounce(value_t!(args.value_of("debounce"), u64).unwrap_or_else(|e| e.exit()));
+    } else if let Some(debounce) = file_config.debounce {
+        builder.debounce(debounce);
     }
 
     // TODO: check how postpone + signal behaves
 
     builder.clear_screen(args.is_present("clear"));
-    builder.restart(args.is_present("restart"));
+    builder.restart(args.is_present("restart") || file_config.restart.unwrap_or(false));
     builder.run_initially(!args.is_present("postpone"));
-    builder.no_shell(args.is_present("no-shell"));
+    builder.no_shell(args.is_present("no-shell") || file_config.shell == Some(false));
     builder.no_meta(args.is_present("no-meta"));
     builder.no_environment(args.is_present("no-environment"));
     builder.no_vcs_ignore(args.is_present("no-vcs-ignore"));
     builder.no_ignore(args.is_present("no-ignore"));
-    builder.poll(args.occurrences_of("poll") > 0);
+    builder.poll(polling);
     builder.watch_when_idle(args.is_present("watch-when-idle"));
 
     let mut config = builder.build()?;
@@ -264,3 +416,43 @@ where
 
     Ok((config, loglevel))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use watchexec::event::{Event, Tag};
+    use watchexec::filter::Filterer;
+
+    #[tokio::test]
+    async fn tag_filter_changes_what_matches() {
+        let filterer = tagged_filterer_for(&["path*=*.rs".to_string()], "/project", "/project")
+            .await
+            .expect("tag filter should parse and register");
+
+        let rust_file = Event {
+            tags: vec![Tag::Path(PathBuf::from("/project/src/main.rs"))],
+            ..Default::default()
+        };
+        assert!(
+            filterer.check_event(&rust_file).unwrap(),
+            "a .rs path should pass the path*=*.rs filter"
+        );
+
+        let other_file = Event {
+            tags: vec![Tag::Path(PathBuf::from("/project/README.md"))],
+            ..Default::default()
+        };
+        assert!(
+            !filterer.check_event(&other_file).unwrap(),
+            "a non-.rs path should be rejected by the path*=*.rs filter"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_tag_filter_is_rejected() {
+        let err = tagged_filterer_for(&["not a filter".to_string()], "/project", "/project")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid --tag-filter"));
+    }
+}
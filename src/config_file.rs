@@ -0,0 +1,76 @@
+//! Discovery and parsing of `.watchexec.toml` files.
+//!
+//! [`discover`] walks up from a directory to find the nearest `.watchexec.toml`; [`load`] parses
+//! one into a [`FileConfig`]. Every field on [`FileConfig`] is optional and merged into
+//! `ConfigBuilder` by the CLI, which only applies a field when the matching flag wasn't passed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error;
+
+/// The name of the config file watchexec looks for in the workdir and its ancestors.
+pub const FILE_NAME: &str = ".watchexec.toml";
+
+/// Settings read from a `.watchexec.toml`. Every field is optional: an absent field means "let
+/// the CLI default or flag decide".
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileConfig {
+    /// Paths to watch, equivalent to one or more `-w`.
+    pub paths: Option<Vec<PathBuf>>,
+
+    /// Patterns to filter on, equivalent to one or more `-f`.
+    pub filters: Option<Vec<String>>,
+
+    /// Patterns to ignore, equivalent to one or more `-i`.
+    pub ignores: Option<Vec<String>>,
+
+    /// Debounce time in milliseconds, equivalent to `-d`.
+    pub debounce: Option<u64>,
+
+    /// Signal to send on changes, equivalent to `-s`.
+    pub signal: Option<String>,
+
+    /// Restart the command if it's still running, equivalent to `-r`.
+    pub restart: Option<bool>,
+
+    /// Force-poll interval in milliseconds, equivalent to `--force-poll`.
+    pub poll_interval: Option<u32>,
+
+    /// Whether to wrap the command in a shell; `false` is equivalent to `-n`/`--no-shell`.
+    pub shell: Option<bool>,
+}
+
+/// Walks up from `start` looking for a [`FILE_NAME`] file, returning the first one found.
+pub fn discover(start: &Path) -> Option<PathBuf> {
+    let mut dir = Some(start);
+    while let Some(d) = dir {
+        let candidate = d.join(FILE_NAME);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        dir = d.parent();
+    }
+
+    None
+}
+
+/// Reads and parses a config file at `path`.
+pub fn load(path: &Path) -> error::Result<FileConfig> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let config = toml::from_str(&content).map_err(|err| err.to_string())?;
+    Ok(config)
+}
+
+/// Discovers and loads the config file for `workdir`, if any. Returns the default (empty)
+/// [`FileConfig`] when none is found, so callers don't need to special-case the absence of a
+/// file.
+pub fn discover_and_load(workdir: &Path) -> error::Result<FileConfig> {
+    match discover(workdir) {
+        Some(path) => load(&path),
+        None => Ok(FileConfig::default()),
+    }
+}